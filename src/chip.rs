@@ -1,35 +1,217 @@
-use crate::components::{AddressableStorage, Register, Memory};
+use std::collections::HashMap;
+
+use crate::components::{AddressableStorage, Register, Memory, Display, Keypad, Quirks, WIDTH, HEIGHT, FONT_START};
 use crate::Instruction;
 use rand::{self, Rng};
 
-pub struct Chip {
-    pub memory: Memory,
+/// Programs are loaded starting at this address, matching the memory layout
+/// real CHIP-8 interpreters reserved for themselves below 0x200.
+pub const PROGRAM_START: u16 = 0x200;
+
+/// A decoded straight-line run of instructions starting at `start`, cached
+/// so `Chip::run` doesn't have to re-read and re-parse the same bytes on
+/// every pass through a tight loop. Ends at (and includes) the first
+/// instruction `ends_basic_block` accepts: a jump, call, return, skip,
+/// draw, or key wait. May end earlier when the cycle budget runs out.
+#[derive(Clone)]
+struct BasicBlock {
+    end: u16,
+    instructions: Vec<Instruction>,
+}
+
+/// Whether `instruction` ends a basic block: anything that can redirect the
+/// program counter away from the next instruction, or that draws (and so is
+/// a natural place to stop batching and let a host redraw).
+fn ends_basic_block(instruction: &Instruction) -> bool {
+    matches!(
+        instruction,
+        Instruction::Jp(_)
+            | Instruction::Call(_)
+            | Instruction::Ret
+            | Instruction::JpV0(_)
+            | Instruction::Se(_, _)
+            | Instruction::SeByte(_, _)
+            | Instruction::Sne(_, _)
+            | Instruction::SneByte(_, _)
+            | Instruction::Drw(_, _, _)
+            | Instruction::SkpVx(_)
+            | Instruction::SknpVx(_)
+            | Instruction::LdVxKey(_)
+    )
+}
+
+/// The CPU, generic over its backing `memory` store so callers can swap in
+/// an instrumented or memory-mapped `AddressableStorage` (to trap accesses
+/// for debugging, enforce the program boundary, or overlay peripherals)
+/// without forking the core. Defaults to the plain `Memory` RAM.
+pub struct Chip<M: AddressableStorage = Memory> {
+    pub memory: M,
     pub register: Register,
     pub program_counter: u16,
     pub stack: Vec<u16>,
+    pub display: Display,
+    pub keypad: Keypad,
+    pub quirks: Quirks,
+    /// Gates the per-instruction `println!` tracing in `execute` so the
+    /// cached-block hot path in `run` does no I/O by default.
+    pub trace: bool,
+    block_cache: HashMap<u16, BasicBlock>,
+}
+
+impl Default for Chip<Memory> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Chip<Memory> {
+    pub fn new() -> Chip<Memory> {
+        Chip::with_memory(Memory::new())
+    }
+
+    pub fn with_register_values(values: &[u8]) -> Chip<Memory> {
+        let mut chip = Chip::new();
+        chip.register = Register::with_values(values);
+        chip
+    }
+
+    pub fn with_quirks(quirks: Quirks) -> Chip<Memory> {
+        let mut chip = Chip::new();
+        chip.quirks = quirks;
+        chip
+    }
+
+    pub fn with_program(bytes: &[u8]) -> Chip<Memory> {
+        let mut chip = Chip::new();
+        chip.load_rom(bytes);
+        chip
+    }
 }
 
-impl Chip {
-    pub fn new() -> Chip {
+impl<M: AddressableStorage> Chip<M> {
+    /// Builds a `Chip` backed by a caller-supplied `AddressableStorage`,
+    /// e.g. a wrapper around `Memory` that logs accesses or maps in
+    /// custom I/O regions.
+    pub fn with_memory(memory: M) -> Chip<M> {
         Chip {
             register: Register::new(),
-            memory: Memory::new(),
+            memory,
             program_counter: 0,
             stack: Vec::new(),
+            display: Display::new(),
+            keypad: Keypad::new(),
+            quirks: Quirks::default(),
+            trace: false,
+            block_cache: HashMap::new(),
         }
     }
 
-    pub fn with_register_values(values: &[u8]) -> Chip {
-        let mut chip = Chip::new();
-        chip.register = Register::with_values(values);
-        chip
+    /// Copies `bytes` into memory starting at `PROGRAM_START` and points the
+    /// program counter at it, ready to be driven by `step`.
+    pub fn load_rom(&mut self, bytes: &[u8]) {
+        for (offset, &byte) in bytes.iter().enumerate() {
+            self.write_memory(PROGRAM_START as usize + offset, byte);
+        }
+        self.program_counter = PROGRAM_START;
+    }
+
+    /// Writes through to `memory`, dropping any cached `BasicBlock` whose
+    /// byte range covers `addr` so self-modifying code is re-decoded.
+    fn write_memory(&mut self, addr: usize, value: u8) {
+        self.memory.set(addr, value);
+        let addr = addr as u16;
+        self.block_cache
+            .retain(|&start, block| !(start..block.end).contains(&addr));
+    }
+
+    /// Decodes forward from `start` until (and including) the first
+    /// control-flow instruction, without executing anything. Stops early
+    /// after `max_instructions` regardless, so a straight-line run that
+    /// never branches can't decode past the current cycle budget.
+    fn decode_block(&self, start: u16, max_instructions: usize) -> BasicBlock {
+        let mut pc = start;
+        let mut instructions = Vec::new();
+        loop {
+            let hi = self.memory.get(pc as usize) as u16;
+            let lo = self.memory.get(pc as usize + 1) as u16;
+            let instruction = Instruction::parse((hi << 8) | lo);
+            pc = pc.wrapping_add(2);
+            let is_boundary = ends_basic_block(&instruction);
+            instructions.push(instruction);
+            if is_boundary || instructions.len() >= max_instructions {
+                break;
+            }
+        }
+        BasicBlock { end: pc, instructions }
+    }
+
+    /// Runs up to `max_cycles` instructions, decoding and caching basic
+    /// blocks so a tight loop is only read and parsed from memory once.
+    pub fn run(&mut self, max_cycles: usize) {
+        let mut executed = 0;
+        while executed < max_cycles {
+            let start = self.program_counter;
+            if !self.block_cache.contains_key(&start) {
+                let block = self.decode_block(start, max_cycles - executed);
+                self.block_cache.insert(start, block);
+            }
+            let block = self.block_cache[&start].clone();
+            for instruction in &block.instructions {
+                self.program_counter += 2;
+                self.execute(instruction);
+                executed += 1;
+                if executed >= max_cycles {
+                    break;
+                }
+            }
+        }
+    }
+
+    /// Fetches the two bytes at `program_counter`, advances it past them,
+    /// decodes the resulting opcode, and executes it.
+    pub fn step(&mut self) {
+        let hi = self.memory.get(self.program_counter as usize) as u16;
+        let lo = self.memory.get(self.program_counter as usize + 1) as u16;
+        let op = (hi << 8) | lo;
+        self.program_counter += 2;
+        let instruction = Instruction::parse(op);
+        self.execute(&instruction);
+    }
+
+    /// The current framebuffer contents, row-major, `true` meaning lit.
+    pub fn framebuffer(&self) -> &[bool; WIDTH * HEIGHT] {
+        self.display.pixels()
+    }
+
+    /// Whether the framebuffer has changed since the last redraw.
+    pub fn is_dirty(&self) -> bool {
+        self.display.is_dirty()
+    }
+
+    /// Marks the current framebuffer contents as drawn, clearing the dirty flag.
+    pub fn clear_dirty(&mut self) {
+        self.display.clear_dirty();
+    }
+
+    /// Decrements the delay and sound timers by one if they're nonzero.
+    /// Intended to be driven at 60 Hz, independently of the CPU step rate.
+    pub fn tick_timers(&mut self) {
+        self.register.delay = self.register.delay.saturating_sub(1);
+        self.register.sound = self.register.sound.saturating_sub(1);
+    }
+
+    /// Whether the sound timer is active and a front-end should be beeping.
+    pub fn is_beeping(&self) -> bool {
+        self.register.sound > 0
     }
 
     pub fn execute(&mut self, instruction: &Instruction) {
-        println!("{0:<15?} ", instruction);
+        if self.trace {
+            println!("{0:<15?} ", instruction);
+        }
         match *instruction {
             Instruction::Sys(_) => unimplemented!(),
-            Instruction::Cls => unimplemented!(),
+            Instruction::Cls => self.display.clear(),
             Instruction::Ret => {
                 if let Some(addr) = self.stack.pop() {
                     self.program_counter = addr & 0xFFF;
@@ -74,19 +256,34 @@ impl Chip {
                 let value_x = self.register.get(vx);
                 let value_y = self.register.get(vy);
                 self.register.set(vx, value_x | value_y);
-                bitwise!(value_x, |, value_y);
+                if self.trace {
+                    bitwise!(value_x, |, value_y);
+                }
+                if self.quirks.reset_vf_on_logic {
+                    self.register.set(0xF, 0);
+                }
             }
             Instruction::And(vx, vy) => {
                 let value_x = self.register.get(vx);
                 let value_y = self.register.get(vy);
                 self.register.set(vx, value_x & value_y);
-                bitwise!(value_x, &, value_y);
+                if self.trace {
+                    bitwise!(value_x, &, value_y);
+                }
+                if self.quirks.reset_vf_on_logic {
+                    self.register.set(0xF, 0);
+                }
             }
             Instruction::Xor(vx, vy) => {
                 let value_x = self.register.get(vx);
                 let value_y = self.register.get(vy);
                 self.register.set(vx, value_x ^ value_y);
-                bitwise!(value_x, ^, value_y);
+                if self.trace {
+                    bitwise!(value_x, ^, value_y);
+                }
+                if self.quirks.reset_vf_on_logic {
+                    self.register.set(0xF, 0);
+                }
             }
             Instruction::Add(vx, vy) => {
                 let value_x = self.register.get(vx) as u16;
@@ -103,7 +300,11 @@ impl Chip {
                 self.register.set(0xF, no_borrow);
                 self.register.set(vx, ((value_x - value_y) & 255) as u8);
             }
-            Instruction::Shr(vx) => {
+            Instruction::Shr(vx, vy) => {
+                if self.quirks.shift_uses_vy {
+                    let value_y = self.register.get(vy);
+                    self.register.set(vx, value_y);
+                }
                 let value_x = self.register.get(vx);
                 let least_sig_bit = value_x & 0b1;
                 self.register.set(0xF, least_sig_bit);
@@ -116,7 +317,11 @@ impl Chip {
                 self.register.set(0xF, no_borrow);
                 self.register.set(vx, ((value_y - value_x) & 255) as u8);
             }
-            Instruction::Shl(vx) => {
+            Instruction::Shl(vx, vy) => {
+                if self.quirks.shift_uses_vy {
+                    let value_y = self.register.get(vy);
+                    self.register.set(vx, value_y);
+                }
                 let value_x = self.register.get(vx);
                 let most_sig_bit = (value_x & 0b10000000) >> 7;
                 self.register.set(0xF, most_sig_bit);
@@ -126,7 +331,13 @@ impl Chip {
                 self.register.i = value;
             }
             Instruction::JpV0(addr) => {
-                self.program_counter = self.register.get(0) as u16 + addr;
+                if self.quirks.jump_with_vx {
+                    let vx = ((addr >> 8) & 0xF) as usize;
+                    let offset = addr & 0x0FF;
+                    self.program_counter = (self.register.get(vx) as u16 + offset) & 0xFFF;
+                } else {
+                    self.program_counter = (self.register.get(0) as u16 + addr) & 0xFFF;
+                }
             }
             Instruction::Rnd(vx, mask) => {
                 let random: u8 = rand::thread_rng().gen::<u8>();
@@ -139,7 +350,77 @@ impl Chip {
             Instruction::LdDelayVx(vx) => {
                 self.register.delay = self.register.get(vx);
             }
+            Instruction::LdSoundVx(vx) => {
+                self.register.sound = self.register.get(vx);
+            }
+            Instruction::Drw(vx, vy, n) => {
+                let x = self.register.get(vx) as usize;
+                let y = self.register.get(vy) as usize;
+                let i = self.register.i as usize;
+                let mut collision = false;
+                for row in 0..n as usize {
+                    let byte = self.memory.get((i + row) & 0xFFF);
+                    if self.display.draw_byte(x, y + row, byte) {
+                        collision = true;
+                    }
+                }
+                self.register.set(0xF, if collision { 1 } else { 0 });
+            }
+            Instruction::AddIVx(vx) => {
+                self.register.i = (self.register.i + self.register.get(vx) as u16) & 0xFFF;
+            }
+            Instruction::LdFVx(vx) => {
+                let digit = (self.register.get(vx) & 0xF) as u16;
+                self.register.i = FONT_START as u16 + digit * 5;
+            }
+            Instruction::Bcd(vx) => {
+                let value = self.register.get(vx);
+                let i = self.register.i as usize;
+                self.write_memory(i & 0xFFF, value / 100);
+                self.write_memory((i + 1) & 0xFFF, (value / 10) % 10);
+                self.write_memory((i + 2) & 0xFFF, value % 10);
+            }
+            Instruction::LdIVx(vx) => {
+                let i = self.register.i as usize;
+                for offset in 0..=vx {
+                    let value = self.register.get(offset);
+                    self.write_memory((i + offset) & 0xFFF, value);
+                }
+                if self.quirks.index_increment_on_load_store {
+                    self.register.i += (vx + 1) as u16;
+                }
+            }
+            Instruction::LdVxI(vx) => {
+                let i = self.register.i as usize;
+                for offset in 0..=vx {
+                    let value = self.memory.get((i + offset) & 0xFFF);
+                    self.register.set(offset, value);
+                }
+                if self.quirks.index_increment_on_load_store {
+                    self.register.i += (vx + 1) as u16;
+                }
+            }
+            Instruction::SkpVx(vx) => {
+                let key = (self.register.get(vx) & 0xF) as usize;
+                if self.keypad.is_down(key) {
+                    self.program_counter += 2;
+                }
+            }
+            Instruction::SknpVx(vx) => {
+                let key = (self.register.get(vx) & 0xF) as usize;
+                if !self.keypad.is_down(key) {
+                    self.program_counter += 2;
+                }
+            }
+            Instruction::LdVxKey(vx) => {
+                match self.keypad.pressed_key() {
+                    Some(key) => self.register.set(vx, key as u8),
+                    None => self.program_counter -= 2,
+                }
+            }
+        }
+        if self.trace {
+            println!("{:?}\n", self.register.values);
         }
-        println!("{:?}\n", self.register.values);
     }
 }
@@ -0,0 +1,61 @@
+pub const WIDTH: usize = 64;
+pub const HEIGHT: usize = 32;
+
+pub struct Display {
+    pixels: [bool; WIDTH * HEIGHT],
+    dirty: bool,
+}
+
+impl Default for Display {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Display {
+    pub fn new() -> Display {
+        Display {
+            pixels: [false; WIDTH * HEIGHT],
+            dirty: false,
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.pixels = [false; WIDTH * HEIGHT];
+        self.dirty = true;
+    }
+
+    pub fn pixels(&self) -> &[bool; WIDTH * HEIGHT] {
+        &self.pixels
+    }
+
+    pub fn is_dirty(&self) -> bool {
+        self.dirty
+    }
+
+    pub fn clear_dirty(&mut self) {
+        self.dirty = false;
+    }
+
+    /// XORs an 8-pixel-wide sprite row into the framebuffer at `(x, y)`,
+    /// wrapping around the screen edges. Returns `true` if any set pixel
+    /// was flipped off, indicating a collision.
+    pub fn draw_byte(&mut self, x: usize, y: usize, byte: u8) -> bool {
+        let mut collision = false;
+        for bit in 0..8 {
+            let pixel_on = (byte >> (7 - bit)) & 1 == 1;
+            if !pixel_on {
+                continue;
+            }
+            let px = (x + bit) % WIDTH;
+            let py = y % HEIGHT;
+            let index = py * WIDTH + px;
+            if self.pixels[index] {
+                collision = true;
+            }
+            self.pixels[index] ^= true;
+        }
+        self.dirty = true;
+        collision
+    }
+}
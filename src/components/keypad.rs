@@ -0,0 +1,32 @@
+pub struct Keypad {
+    keys: [bool; 16],
+}
+
+impl Default for Keypad {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keypad {
+    pub fn new() -> Keypad {
+        Keypad { keys: [false; 16] }
+    }
+
+    pub fn press(&mut self, key: usize) {
+        self.keys[key] = true;
+    }
+
+    pub fn release(&mut self, key: usize) {
+        self.keys[key] = false;
+    }
+
+    pub fn is_down(&self, key: usize) -> bool {
+        self.keys[key]
+    }
+
+    /// The lowest-numbered key currently held down, if any.
+    pub fn pressed_key(&self) -> Option<usize> {
+        self.keys.iter().position(|&down| down)
+    }
+}
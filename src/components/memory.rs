@@ -1,19 +1,50 @@
-use components::AddressableStorage;
+use crate::components::AddressableStorage;
+
+/// Address the built-in hex digit sprites are loaded at.
+pub const FONT_START: usize = 0x000;
+
+/// The built-in 4x5 hex digit sprites (0-F), 5 bytes each, addressed by
+/// `Fx29` via `FONT_START + digit * 5`.
+pub const FONT_SET: [u8; 80] = [
+    0xF0, 0x90, 0x90, 0x90, 0xF0, // 0
+    0x20, 0x60, 0x20, 0x20, 0x70, // 1
+    0xF0, 0x10, 0xF0, 0x80, 0xF0, // 2
+    0xF0, 0x10, 0xF0, 0x10, 0xF0, // 3
+    0x90, 0x90, 0xF0, 0x10, 0x10, // 4
+    0xF0, 0x80, 0xF0, 0x10, 0xF0, // 5
+    0xF0, 0x80, 0xF0, 0x90, 0xF0, // 6
+    0xF0, 0x10, 0x20, 0x40, 0x40, // 7
+    0xF0, 0x90, 0xF0, 0x90, 0xF0, // 8
+    0xF0, 0x90, 0xF0, 0x10, 0xF0, // 9
+    0xF0, 0x90, 0xF0, 0x90, 0x90, // A
+    0xE0, 0x90, 0xE0, 0x90, 0xE0, // B
+    0xF0, 0x80, 0x80, 0x80, 0xF0, // C
+    0xE0, 0x90, 0x90, 0x90, 0xE0, // D
+    0xF0, 0x80, 0xF0, 0x80, 0xF0, // E
+    0xF0, 0x80, 0xF0, 0x80, 0x80, // F
+];
 
 pub struct Memory {
     pub values: [u8; 4096],
 }
 
+impl Default for Memory {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Memory {
     pub fn new() -> Memory {
-        Memory { values: [0u8; 4096] }
+        let mut values = [0u8; 4096];
+        values[FONT_START..FONT_START + FONT_SET.len()].copy_from_slice(&FONT_SET);
+        Memory { values }
     }
 
     pub fn with_values(values: &[u8]) -> Memory {
         let mut vals = [0u8; 4096];
-        for i in 0..::std::cmp::min(values.len(), 4096) {
-            vals[i] = values[i];
-        }
+        let len = ::std::cmp::min(values.len(), 4096);
+        vals[..len].copy_from_slice(&values[..len]);
         Memory { values: vals }
     }
 }
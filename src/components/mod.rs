@@ -1,7 +1,13 @@
 mod addressable_storage;
 mod register;
 mod memory;
+mod display;
+mod keypad;
+mod quirks;
 
 pub use self::addressable_storage::AddressableStorage;
 pub use self::register::Register;
-pub use self::memory::Memory;
+pub use self::memory::{Memory, FONT_START};
+pub use self::display::{Display, WIDTH, HEIGHT};
+pub use self::keypad::Keypad;
+pub use self::quirks::Quirks;
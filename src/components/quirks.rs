@@ -0,0 +1,44 @@
+/// CHIP-8 implementations disagree on the exact behavior of a handful of
+/// opcodes. `Quirks` selects between them so the same ROM can be run against
+/// whichever variant it was written for.
+#[derive(Debug, Default, Clone, Copy)]
+pub struct Quirks {
+    /// `8xy6`/`8xyE` (SHR/SHL) copy Vy into Vx before shifting, as the
+    /// original COSMAC VIP interpreter did, instead of shifting Vx in place.
+    pub shift_uses_vy: bool,
+
+    /// `Fx55`/`Fx65` (load/store through I) leave I incremented by `x + 1`
+    /// afterward, as the original COSMAC VIP interpreter did.
+    pub index_increment_on_load_store: bool,
+
+    /// `Bnnn` (JP V0, addr) treats the high nibble of the address as a
+    /// register index and jumps to `Vx + the remaining offset`, the
+    /// SUPER-CHIP `BXNN` behavior, instead of always using V0.
+    pub jump_with_vx: bool,
+
+    /// `8xy1`/`8xy2`/`8xy3` (OR/AND/XOR) reset VF to 0, a side effect some
+    /// original CHIP-8 interpreters had.
+    pub reset_vf_on_logic: bool,
+}
+
+impl Quirks {
+    /// Behavior of the original COSMAC VIP CHIP-8 interpreter.
+    pub fn cosmac_vip() -> Quirks {
+        Quirks {
+            shift_uses_vy: true,
+            index_increment_on_load_store: true,
+            jump_with_vx: false,
+            reset_vf_on_logic: true,
+        }
+    }
+
+    /// Behavior modern SUPER-CHIP-derived interpreters settled on.
+    pub fn super_chip() -> Quirks {
+        Quirks {
+            shift_uses_vy: false,
+            index_increment_on_load_store: false,
+            jump_with_vx: true,
+            reset_vf_on_logic: false,
+        }
+    }
+}
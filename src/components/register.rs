@@ -8,6 +8,12 @@ pub struct Register {
     pub values: [u8; 16],
 }
 
+impl Default for Register {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Register {
     pub fn new() -> Register {
         Register {
@@ -20,9 +26,8 @@ impl Register {
 
     pub fn with_values(values: &[u8]) -> Register {
         let mut vals = [0u8; 16];
-        for i in 0..::std::cmp::min(values.len(), 16) {
-            vals[i] = values[i];
-        }
+        let len = ::std::cmp::min(values.len(), 16);
+        vals[..len].copy_from_slice(&values[..len]);
         Register {
             delay: 0,
             i: 0,
@@ -1,4 +1,4 @@
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub enum Instruction {
     /// 0nnn - SYS addr
     /// Jump to a machine code routine at nnn.
@@ -92,8 +92,9 @@ pub enum Instruction {
     /// 8xy6 - SHR Vx {, Vy}
     /// Set Vx = Vx SHR 1.
     /// If the least-significant bit of Vx is 1, then VF is set to 1, otherwise 0.
-    /// Then Vx is divided by 2.
-    Shr(usize), // Shift Right
+    /// Then Vx is divided by 2. Under the `shift_uses_vy` quirk, Vy is
+    /// copied into Vx before shifting.
+    Shr(usize, usize), // Shift Right
 
     /// 8xy7 - SUBN Vx, Vy
     /// Set Vx = Vy - Vx, set VF = NOT borrow.
@@ -104,8 +105,9 @@ pub enum Instruction {
     /// 8xyE - SHL Vx {, Vy}
     /// Set Vx = Vx SHL 1.
     /// If the most-significant bit of Vx is 1, then VF is set to 1, otherwise to 0.
-    /// Then Vx is multiplied by 2.
-    Shl(usize), // Shift Left
+    /// Then Vx is multiplied by 2. Under the `shift_uses_vy` quirk, Vy is
+    /// copied into Vx before shifting.
+    Shl(usize, usize), // Shift Left
 
     /// 9xy0 - SNE Vx, Vy
     /// Skip next instruction if Vx != Vy.
@@ -138,22 +140,103 @@ pub enum Instruction {
     /// Set delay timer = Vx
     /// DT is set equal to the value of Vx.
     LdDelayVx(usize),
+
+    /// Dxyn - DRW Vx, Vy, nibble
+    /// Display n-byte sprite starting at memory location I at (Vx, Vy), set VF = collision.
+    /// Each byte is drawn as 8 horizontal pixels, XORed onto the existing screen.
+    /// If this causes any pixel to be erased, VF is set to 1, otherwise 0.
+    /// Sprites wrap around to the opposite side of the screen.
+    Drw(usize, usize, u8),
+
+    /// Fx1E - ADD I, Vx
+    /// Set I = I + Vx.
+    /// The values of I and Vx are added, and the results are stored in I.
+    AddIVx(usize),
+
+    /// Fx29 - LD F, Vx
+    /// Set I = location of sprite for digit Vx.
+    /// The value of I is set to the address of the built-in hex font sprite
+    /// corresponding to the low nibble of Vx.
+    LdFVx(usize),
+
+    /// Fx33 - LD B, Vx
+    /// Store BCD representation of Vx in memory locations I, I+1, and I+2.
+    /// The interpreter takes the decimal value of Vx, and places the
+    /// hundreds digit in memory at location I, the tens digit at location
+    /// I+1, and the units digit at location I+2.
+    Bcd(usize),
+
+    /// Fx55 - LD [I], Vx
+    /// Store registers V0 through Vx in memory starting at location I.
+    LdIVx(usize),
+
+    /// Fx65 - LD Vx, [I]
+    /// Read registers V0 through Vx from memory starting at location I.
+    LdVxI(usize),
+
+    /// Ex9E - SKP Vx
+    /// Skip next instruction if key with the value of Vx is pressed.
+    /// The interpreter checks the keyboard, and if the key corresponding to
+    /// the value of Vx is currently in the down position, PC is increased by 2.
+    SkpVx(usize),
+
+    /// ExA1 - SKNP Vx
+    /// Skip next instruction if key with the value of Vx is not pressed.
+    /// The interpreter checks the keyboard, and if the key corresponding to
+    /// the value of Vx is currently in the up position, PC is increased by 2.
+    SknpVx(usize),
+
+    /// Fx0A - LD Vx, K
+    /// Wait for a key press, store the value of the key in Vx.
+    /// All execution stops until a key is pressed, then the value of that
+    /// key is stored in Vx.
+    LdVxKey(usize),
+
+    /// Fx18 - LD ST, Vx
+    /// Set sound timer = Vx.
+    /// ST is set equal to the value of Vx.
+    LdSoundVx(usize),
 }
 
 impl Instruction {
     pub fn parse(op: u16) -> Instruction {
         let instruction = (op >> 12 & 15, (op >> 8) & 15, (op >> 4) & 15, op & 15);
         match instruction {
+            (0, 0, 0xE, 0) => Instruction::Cls,
+            (0, 0, 0xE, 0xE) => Instruction::Ret,
+            (0, _, _, _) => Instruction::Sys(op & 0x0FFF),
+            (1, _, _, _) => Instruction::Jp(op & 0x0FFF),
+            (2, _, _, _) => Instruction::Call(op & 0x0FFF),
+            (3, x, a, b) => Instruction::SeByte(x as usize, (((a << 4) + b) & 255) as u8),
+            (4, x, a, b) => Instruction::SneByte(x as usize, (((a << 4) + b) & 255) as u8),
+            (5, x, y, 0) => Instruction::Se(x as usize, y as usize),
             (6, x, a, b) => Instruction::LdByte(x as usize, (((a << 4) + b) & 255) as u8),
+            (7, x, a, b) => Instruction::AddByte(x as usize, (((a << 4) + b) & 255) as u8),
             (8, x, y, 0) => Instruction::Ld(x as usize, y as usize),
             (8, x, y, 1) => Instruction::Or(x as usize, y as usize),
             (8, x, y, 2) => Instruction::And(x as usize, y as usize),
             (8, x, y, 3) => Instruction::Xor(x as usize, y as usize),
             (8, x, y, 4) => Instruction::Add(x as usize, y as usize),
             (8, x, y, 5) => Instruction::Sub(x as usize, y as usize),
-            (8, x, _, 6) => Instruction::Shr(x as usize),
+            (8, x, y, 6) => Instruction::Shr(x as usize, y as usize),
             (8, x, y, 7) => Instruction::Subn(x as usize, y as usize),
-            (8, x, _, 0xE) => Instruction::Shl(x as usize),
+            (8, x, y, 0xE) => Instruction::Shl(x as usize, y as usize),
+            (9, x, y, 0) => Instruction::Sne(x as usize, y as usize),
+            (0xA, _, _, _) => Instruction::Ldi(op & 0x0FFF),
+            (0xB, _, _, _) => Instruction::JpV0(op & 0x0FFF),
+            (0xC, x, a, b) => Instruction::Rnd(x as usize, (((a << 4) + b) & 255) as u8),
+            (0xD, x, y, n) => Instruction::Drw(x as usize, y as usize, n as u8),
+            (0xE, x, 9, 0xE) => Instruction::SkpVx(x as usize),
+            (0xE, x, 0xA, 1) => Instruction::SknpVx(x as usize),
+            (0xF, x, 0, 7) => Instruction::LdVxDelay(x as usize),
+            (0xF, x, 0, 0xA) => Instruction::LdVxKey(x as usize),
+            (0xF, x, 1, 5) => Instruction::LdDelayVx(x as usize),
+            (0xF, x, 1, 8) => Instruction::LdSoundVx(x as usize),
+            (0xF, x, 1, 0xE) => Instruction::AddIVx(x as usize),
+            (0xF, x, 2, 9) => Instruction::LdFVx(x as usize),
+            (0xF, x, 3, 3) => Instruction::Bcd(x as usize),
+            (0xF, x, 5, 5) => Instruction::LdIVx(x as usize),
+            (0xF, x, 6, 5) => Instruction::LdVxI(x as usize),
             _ => unimplemented!(),
         }
     }
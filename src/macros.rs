@@ -9,3 +9,8 @@ macro_rules! bitwise {
         }
     );
 }
+
+#[cfg(test)]
+macro_rules! register_eq {
+    ($chip:tt, $vx:expr, $value:expr) => (assert_eq!($chip.register.get($vx), $value);)
+}
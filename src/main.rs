@@ -1,23 +1,29 @@
 extern crate chip_8;
 
-use chip_8::{Chip, Instruction};
+use std::env;
+use std::fs;
+use std::thread;
+use std::time::Duration;
+
+use chip_8::Chip;
+
+// The CPU and timers run at different rates: ~500 Hz keeps instructions
+// snappy while the timers must decay at exactly 60 Hz regardless of how
+// fast instructions are being executed.
+const INSTRUCTIONS_PER_SECOND: u32 = 500;
+const TIMER_HZ: u32 = 60;
 
 fn main() {
-    let mut chip = Chip::new();
-    let instructions = [Instruction::LdByte(0, 100),
-                        Instruction::Ld(1, 0),
-                        Instruction::Shl(0),
-                        Instruction::Shr(1),
-                        Instruction::Sub(0, 1),
-                        Instruction::Add(1, 0),
-                        Instruction::LdByte(2, 57),
-                        Instruction::Xor(1, 2),
-                        Instruction::Ld(3, 1),
-                        Instruction::And(3, 2),
-                        Instruction::Rnd(5, 255),
-                        Instruction::Rnd(5, 10)];
+    let path = env::args().nth(1).expect("usage: chip_8 <rom path>");
+    let rom = fs::read(path).expect("failed to read ROM file");
 
-    for ins in &instructions {
-        chip.execute(ins);
+    let mut chip = Chip::with_program(&rom);
+    let instructions_per_tick = INSTRUCTIONS_PER_SECOND / TIMER_HZ;
+    loop {
+        for _ in 0..instructions_per_tick {
+            chip.step();
+        }
+        chip.tick_timers();
+        thread::sleep(Duration::from_secs(1) / TIMER_HZ);
     }
 }
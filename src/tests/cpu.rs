@@ -0,0 +1,69 @@
+use crate::{Chip, Instruction};
+use crate::components::AddressableStorage;
+use crate::chip::PROGRAM_START;
+
+#[test]
+fn load_rom_copies_bytes_starting_at_0x200() {
+    let mut chip = Chip::new();
+    chip.load_rom(&[0x12, 0x34]);
+    assert_eq!(chip.memory.get(PROGRAM_START as usize), 0x12);
+    assert_eq!(chip.memory.get(PROGRAM_START as usize + 1), 0x34);
+    assert_eq!(chip.program_counter, PROGRAM_START);
+}
+
+#[test]
+fn with_program_is_ready_to_step() {
+    let mut chip = Chip::with_program(&[0x60, 0x2A]); // LD V0, 0x2A
+    chip.step();
+    register_eq!(chip, 0, 0x2A);
+    assert_eq!(chip.program_counter, PROGRAM_START + 2);
+}
+
+#[test]
+fn step_advances_program_counter_by_two() {
+    let mut chip = Chip::with_program(&[0x60, 0x01, 0x61, 0x02]);
+    chip.step();
+    chip.step();
+    register_eq!(chip, 0, 1);
+    register_eq!(chip, 1, 2);
+    assert_eq!(chip.program_counter, PROGRAM_START + 4);
+}
+
+#[test]
+fn jp_sets_program_counter_without_double_advancing() {
+    let mut chip = Chip::with_program(&[0x12, 0x00]); // JP 0x200
+    chip.step();
+    assert_eq!(chip.program_counter, PROGRAM_START);
+}
+
+#[test]
+fn call_then_ret_returns_to_instruction_after_call() {
+    let mut chip = Chip::with_program(&[0x22, 0x04, 0x00, 0x00, 0x00, 0xEE]);
+    chip.step(); // CALL 0x204
+    assert_eq!(chip.program_counter, PROGRAM_START + 4);
+    chip.step(); // RET
+    assert_eq!(chip.program_counter, PROGRAM_START + 2);
+}
+
+#[test]
+fn parse_covers_every_opcode_group_used_by_the_existing_variants() {
+    assert!(matches!(Instruction::parse(0x00E0), Instruction::Cls));
+    assert!(matches!(Instruction::parse(0x00EE), Instruction::Ret));
+    assert!(matches!(Instruction::parse(0x0123), Instruction::Sys(0x123)));
+    assert!(matches!(Instruction::parse(0x1234), Instruction::Jp(0x234)));
+    assert!(matches!(Instruction::parse(0x2345), Instruction::Call(0x345)));
+    assert!(matches!(Instruction::parse(0x3A12), Instruction::SeByte(0xA, 0x12)));
+    assert!(matches!(Instruction::parse(0x4A12), Instruction::SneByte(0xA, 0x12)));
+    assert!(matches!(Instruction::parse(0x5AB0), Instruction::Se(0xA, 0xB)));
+    assert!(matches!(Instruction::parse(0x6A12), Instruction::LdByte(0xA, 0x12)));
+    assert!(matches!(Instruction::parse(0x7A12), Instruction::AddByte(0xA, 0x12)));
+    assert!(matches!(Instruction::parse(0x8AB0), Instruction::Ld(0xA, 0xB)));
+    assert!(matches!(Instruction::parse(0x9AB0), Instruction::Sne(0xA, 0xB)));
+    assert!(matches!(Instruction::parse(0xA123), Instruction::Ldi(0x123)));
+    assert!(matches!(Instruction::parse(0xB123), Instruction::JpV0(0x123)));
+    assert!(matches!(Instruction::parse(0xC0FF), Instruction::Rnd(0, 0xFF)));
+    assert!(matches!(Instruction::parse(0xDAB5), Instruction::Drw(0xA, 0xB, 5)));
+    assert!(matches!(Instruction::parse(0xEA9E), Instruction::SkpVx(0xA)));
+    assert!(matches!(Instruction::parse(0xF007), Instruction::LdVxDelay(0)));
+    assert!(matches!(Instruction::parse(0xF015), Instruction::LdDelayVx(0)));
+}
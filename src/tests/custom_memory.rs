@@ -0,0 +1,38 @@
+use crate::Chip;
+use crate::components::{AddressableStorage, Memory};
+
+/// A minimal instrumented store used to prove `Chip` can be driven by any
+/// `AddressableStorage`, not just the built-in `Memory`.
+struct CountingMemory {
+    inner: Memory,
+    writes: usize,
+}
+
+impl CountingMemory {
+    fn new() -> CountingMemory {
+        CountingMemory {
+            inner: Memory::new(),
+            writes: 0,
+        }
+    }
+}
+
+impl AddressableStorage for CountingMemory {
+    fn set(&mut self, key: usize, value: u8) {
+        self.writes += 1;
+        self.inner.set(key, value);
+    }
+
+    fn get(&self, key: usize) -> u8 {
+        self.inner.get(key)
+    }
+}
+
+#[test]
+fn with_memory_drives_the_cpu_through_a_custom_store() {
+    let mut chip = Chip::with_memory(CountingMemory::new());
+    chip.load_rom(&[0x60, 0x2A]); // LD V0, 0x2A
+    chip.step();
+    assert_eq!(chip.register.get(0), 0x2A);
+    assert!(chip.memory.writes > 0);
+}
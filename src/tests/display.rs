@@ -0,0 +1,56 @@
+use crate::{Chip, Instruction};
+use crate::components::AddressableStorage;
+
+#[test]
+fn cls_clears_the_framebuffer() {
+    let mut chip = Chip::new();
+    chip.register.i = 0;
+    chip.memory.set(0, 0xFF);
+    chip.execute(&Instruction::Drw(0, 0, 1));
+    assert!(chip.framebuffer().iter().any(|&pixel| pixel));
+
+    chip.execute(&Instruction::Cls);
+    assert!(chip.framebuffer().iter().all(|&pixel| !pixel));
+}
+
+#[test]
+fn drw_sets_pixels_and_no_collision_on_first_draw() {
+    let mut chip = Chip::new();
+    chip.register.i = 0;
+    chip.memory.set(0, 0b1111_0000);
+    chip.execute(&Instruction::Drw(0, 0, 1));
+
+    for x in 0..4 {
+        assert!(chip.framebuffer()[x]);
+    }
+    for x in 4..8 {
+        assert!(!chip.framebuffer()[x]);
+    }
+    register_eq!(chip, 0xF, 0);
+}
+
+#[test]
+fn drw_xors_and_reports_collision() {
+    let mut chip = Chip::new();
+    chip.register.i = 0;
+    chip.memory.set(0, 0b1000_0000);
+    chip.execute(&Instruction::Drw(0, 0, 1));
+    register_eq!(chip, 0xF, 0);
+
+    chip.execute(&Instruction::Drw(0, 0, 1));
+    assert!(!chip.framebuffer()[0]);
+    register_eq!(chip, 0xF, 1);
+}
+
+#[test]
+fn drw_wraps_around_screen_edges() {
+    let mut chip = Chip::new();
+    chip.register.i = 0;
+    chip.register.set(0, 63);
+    chip.register.set(1, 31);
+    chip.memory.set(0, 0b1100_0000);
+    chip.execute(&Instruction::Drw(0, 1, 1));
+
+    assert!(chip.framebuffer()[31 * 64 + 63]);
+    assert!(chip.framebuffer()[31 * 64]);
+}
@@ -0,0 +1,48 @@
+use crate::{Chip, Instruction};
+use crate::components::AddressableStorage;
+
+#[test]
+fn skp_skips_when_key_is_down() {
+    let mut chip = Chip::with_register_values(&[5]);
+    chip.keypad.press(5);
+    let pc = chip.program_counter;
+    chip.execute(&Instruction::SkpVx(0));
+    assert_eq!(chip.program_counter, pc + 2);
+}
+
+#[test]
+fn skp_does_not_skip_when_key_is_up() {
+    let mut chip = Chip::with_register_values(&[5]);
+    let pc = chip.program_counter;
+    chip.execute(&Instruction::SkpVx(0));
+    assert_eq!(chip.program_counter, pc);
+}
+
+#[test]
+fn sknp_skips_when_key_is_up() {
+    let mut chip = Chip::with_register_values(&[5]);
+    let pc = chip.program_counter;
+    chip.execute(&Instruction::SknpVx(0));
+    assert_eq!(chip.program_counter, pc + 2);
+}
+
+#[test]
+fn sknp_does_not_skip_when_key_is_down() {
+    let mut chip = Chip::with_register_values(&[5]);
+    chip.keypad.press(5);
+    let pc = chip.program_counter;
+    chip.execute(&Instruction::SknpVx(0));
+    assert_eq!(chip.program_counter, pc);
+}
+
+#[test]
+fn ld_vx_key_blocks_by_rewinding_pc_until_a_key_is_pressed() {
+    let mut chip = Chip::with_program(&[0xF0, 0x0A]); // LD V0, K
+    chip.step();
+    assert_eq!(chip.program_counter, 0x200); // re-executes the same opcode
+
+    chip.keypad.press(7);
+    chip.step();
+    register_eq!(chip, 0, 7);
+    assert_eq!(chip.program_counter, 0x202);
+}
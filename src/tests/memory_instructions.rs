@@ -0,0 +1,50 @@
+use crate::{Chip, Instruction};
+use crate::components::AddressableStorage;
+
+#[test]
+fn add_i_vx_adds_register_into_i() {
+    let mut chip = Chip::with_register_values(&[5]);
+    chip.register.i = 10;
+    chip.execute(&Instruction::AddIVx(0));
+    assert_eq!(chip.register.i, 15);
+}
+
+#[test]
+fn ld_f_vx_points_i_at_the_digit_sprite() {
+    let mut chip = Chip::with_register_values(&[3]);
+    chip.execute(&Instruction::LdFVx(0));
+    assert_eq!(chip.register.i, 3 * 5);
+}
+
+#[test]
+fn bcd_splits_value_into_hundreds_tens_units() {
+    let mut chip = Chip::with_register_values(&[253]);
+    chip.register.i = 0x300;
+    chip.execute(&Instruction::Bcd(0));
+    assert_eq!(chip.memory.get(0x300), 2);
+    assert_eq!(chip.memory.get(0x301), 5);
+    assert_eq!(chip.memory.get(0x302), 3);
+}
+
+#[test]
+fn ld_i_vx_stores_v0_through_vx_in_memory() {
+    let mut chip = Chip::with_register_values(&[10, 20, 30]);
+    chip.register.i = 0x300;
+    chip.execute(&Instruction::LdIVx(2));
+    assert_eq!(chip.memory.get(0x300), 10);
+    assert_eq!(chip.memory.get(0x301), 20);
+    assert_eq!(chip.memory.get(0x302), 30);
+}
+
+#[test]
+fn ld_vx_i_loads_v0_through_vx_from_memory() {
+    let mut chip = Chip::new();
+    chip.register.i = 0x300;
+    chip.memory.set(0x300, 10);
+    chip.memory.set(0x301, 20);
+    chip.memory.set(0x302, 30);
+    chip.execute(&Instruction::LdVxI(2));
+    register_eq!(chip, 0, 10);
+    register_eq!(chip, 1, 20);
+    register_eq!(chip, 2, 30);
+}
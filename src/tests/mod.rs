@@ -0,0 +1,9 @@
+mod register_instructions;
+mod cpu;
+mod display;
+mod memory_instructions;
+mod keypad_instructions;
+mod timers;
+mod quirks;
+mod custom_memory;
+mod run;
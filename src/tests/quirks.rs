@@ -0,0 +1,61 @@
+use crate::{Chip, Instruction};
+use crate::components::{AddressableStorage, Quirks};
+
+#[test]
+fn shift_uses_vy_quirk_copies_vy_before_shifting() {
+    let mut chip = Chip::with_quirks(Quirks::cosmac_vip());
+    chip.register.set(0, 0xFF);
+    chip.register.set(1, 0b0000_0010);
+    chip.execute(&Instruction::Shr(0, 1));
+    register_eq!(chip, 0, 0b0000_0001);
+
+    let mut default_chip = Chip::new();
+    default_chip.register.set(0, 0xFF);
+    default_chip.register.set(1, 0b0000_0010);
+    default_chip.execute(&Instruction::Shr(0, 1));
+    register_eq!(default_chip, 0, 0x7F);
+}
+
+#[test]
+fn index_increment_on_load_store_quirk_advances_i() {
+    let mut chip = Chip::with_quirks(Quirks::cosmac_vip());
+    chip.register.i = 0x300;
+    chip.execute(&Instruction::LdIVx(2));
+    assert_eq!(chip.register.i, 0x303);
+
+    let mut default_chip = Chip::new();
+    default_chip.register.i = 0x300;
+    default_chip.execute(&Instruction::LdIVx(2));
+    assert_eq!(default_chip.register.i, 0x300);
+}
+
+#[test]
+fn jump_with_vx_quirk_uses_the_high_nibble_as_a_register() {
+    let mut chip = Chip::with_quirks(Quirks::super_chip());
+    chip.register.set(2, 0x10);
+    chip.execute(&Instruction::JpV0(0x2FF));
+    assert_eq!(chip.program_counter, 0x10 + 0xFF);
+
+    let mut default_chip = Chip::new();
+    default_chip.register.set(2, 0x10);
+    default_chip.register.set(0, 5);
+    default_chip.execute(&Instruction::JpV0(0x2FF));
+    assert_eq!(default_chip.program_counter, 5 + 0x2FF);
+}
+
+#[test]
+fn reset_vf_on_logic_quirk_clears_vf_after_bitwise_ops() {
+    let mut chip = Chip::with_quirks(Quirks::cosmac_vip());
+    chip.register.set(0xF, 1);
+    chip.register.set(0, 0b1010);
+    chip.register.set(1, 0b0101);
+    chip.execute(&Instruction::Or(0, 1));
+    register_eq!(chip, 0xF, 0);
+
+    let mut default_chip = Chip::new();
+    default_chip.register.set(0xF, 1);
+    default_chip.register.set(0, 0b1010);
+    default_chip.register.set(1, 0b0101);
+    default_chip.execute(&Instruction::Or(0, 1));
+    register_eq!(default_chip, 0xF, 1);
+}
@@ -1,10 +1,6 @@
 use crate::{Chip, Instruction};
 use crate::components::AddressableStorage;
 
-macro_rules! register_eq {
-    ($chip:tt, $vx:expr, $value:expr) => (assert_eq!($chip.register.get($vx), $value);)
-}
-
 #[test]
 fn load_byte() {
     let mut chip = Chip::new();
@@ -100,7 +96,7 @@ fn add_then_subtract_restores_state_with_overflows() {
 #[test]
 fn shift_right_with_odd_number_sets_vf_flag() {
     let mut chip = Chip::with_register_values(&[5]);
-    chip.execute(&Instruction::Shr(0));
+    chip.execute(&Instruction::Shr(0, 0));
     register_eq!(chip, 0, 2);
     register_eq!(chip, 0xF, 1);
 }
@@ -108,7 +104,7 @@ fn shift_right_with_odd_number_sets_vf_flag() {
 #[test]
 fn shift_right_with_even_number_does_not_set_vf_flag() {
     let mut chip = Chip::with_register_values(&[6]);
-    chip.execute(&Instruction::Shr(0));
+    chip.execute(&Instruction::Shr(0, 0));
     register_eq!(chip, 0, 3);
     register_eq!(chip, 0xF, 0);
 }
@@ -116,27 +112,27 @@ fn shift_right_with_even_number_does_not_set_vf_flag() {
 #[test]
 fn shift_left_then_shift_right_restores_state() {
     let mut chip = Chip::with_register_values(&[100]);
-    chip.execute(&Instruction::Shl(0));
+    chip.execute(&Instruction::Shl(0, 0));
     register_eq!(chip, 0, 200);
 
-    chip.execute(&Instruction::Shr(0));
+    chip.execute(&Instruction::Shr(0, 0));
     register_eq!(chip, 0, 100);
 
-    chip.execute(&Instruction::Shr(0));
+    chip.execute(&Instruction::Shr(0, 0));
     register_eq!(chip, 0, 50);
 
-    chip.execute(&Instruction::Shl(0));
+    chip.execute(&Instruction::Shl(0, 0));
     register_eq!(chip, 0, 100);
 }
 
 #[test]
 fn shift_right_then_shift_left_loses_info_with_odd_number() {
     let mut chip = Chip::with_register_values(&[5]);
-    chip.execute(&Instruction::Shr(0));
+    chip.execute(&Instruction::Shr(0, 0));
     register_eq!(chip, 0, 2);
     register_eq!(chip, 0xF, 1);
 
-    chip.execute(&Instruction::Shl(0));
+    chip.execute(&Instruction::Shl(0, 0));
     register_eq!(chip, 0, 4);
     register_eq!(chip, 0xF, 0);
 }
@@ -144,7 +140,7 @@ fn shift_right_then_shift_left_loses_info_with_odd_number() {
 #[test]
 fn shift_left_with_overflow_sets_vf_flag() {
     let mut chip = Chip::with_register_values(&[150]);
-    chip.execute(&Instruction::Shl(0));
+    chip.execute(&Instruction::Shl(0, 0));
     register_eq!(chip, 0, 44);
     register_eq!(chip, 0xF, 1);
 }
@@ -152,11 +148,11 @@ fn shift_left_with_overflow_sets_vf_flag() {
 #[test]
 fn shift_left_then_shift_right_loses_info_with_overflow() {
     let mut chip = Chip::with_register_values(&[150]);
-    chip.execute(&Instruction::Shl(0));
+    chip.execute(&Instruction::Shl(0, 0));
     register_eq!(chip, 0, 44);
     register_eq!(chip, 0xF, 1);
 
-    chip.execute(&Instruction::Shr(0));
+    chip.execute(&Instruction::Shr(0, 0));
     register_eq!(chip, 0, 22);
     register_eq!(chip, 0xF, 0);
 }
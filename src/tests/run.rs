@@ -0,0 +1,40 @@
+use crate::Chip;
+use crate::components::AddressableStorage;
+
+#[test]
+fn run_executes_straight_line_code_up_to_the_cycle_budget() {
+    let mut chip = Chip::with_program(&[0x60, 0x01, 0x61, 0x02, 0x62, 0x03]);
+    chip.run(2);
+    assert_eq!(chip.register.get(0), 1);
+    assert_eq!(chip.register.get(1), 2);
+    assert_eq!(chip.register.get(2), 0);
+}
+
+#[test]
+fn run_follows_jumps_across_block_boundaries() {
+    // 0x200: JP 0x204 ; 0x202: LD V0, 0xFF (skipped) ; 0x204: LD V1, 5
+    let mut chip = Chip::with_program(&[0x12, 0x04, 0x60, 0xFF, 0x61, 0x05]);
+    chip.run(2);
+    assert_eq!(chip.register.get(0), 0);
+    assert_eq!(chip.register.get(1), 5);
+}
+
+#[test]
+fn run_reuses_the_cached_block_on_a_tight_loop() {
+    // 0x200: LD V0, 1 ; 0x202: ADD V1, 1 ; 0x204: JP 0x200
+    let mut chip = Chip::with_program(&[0x60, 0x01, 0x71, 0x01, 0x12, 0x00]);
+    chip.run(30); // 10 full passes through the loop
+    assert_eq!(chip.register.get(1), 10);
+}
+
+#[test]
+fn self_modifying_write_invalidates_the_cached_block() {
+    // 0x200: LD V0, 1 ; 0x202: LD V1, 2 (will be overwritten to LD V1, 9)
+    let mut chip = Chip::with_program(&[0x60, 0x01, 0x61, 0x02]);
+    chip.run(2);
+    assert_eq!(chip.register.get(1), 2);
+
+    chip.load_rom(&[0x60, 0x01, 0x61, 0x09]);
+    chip.run(2);
+    assert_eq!(chip.register.get(1), 9);
+}
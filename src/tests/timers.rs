@@ -0,0 +1,38 @@
+use crate::Chip;
+
+#[test]
+fn tick_timers_decrements_delay_and_sound() {
+    let mut chip = Chip::new();
+    chip.register.delay = 2;
+    chip.register.sound = 1;
+
+    chip.tick_timers();
+    assert_eq!(chip.register.delay, 1);
+    assert_eq!(chip.register.sound, 0);
+
+    chip.tick_timers();
+    assert_eq!(chip.register.delay, 0);
+    assert_eq!(chip.register.sound, 0);
+}
+
+#[test]
+fn tick_timers_does_not_underflow_past_zero() {
+    let mut chip = Chip::new();
+    chip.tick_timers();
+    assert_eq!(chip.register.delay, 0);
+    assert_eq!(chip.register.sound, 0);
+}
+
+#[test]
+fn is_beeping_tracks_the_sound_timer() {
+    let mut chip = Chip::new();
+    assert!(!chip.is_beeping());
+
+    chip.register.sound = 3;
+    assert!(chip.is_beeping());
+
+    chip.tick_timers();
+    chip.tick_timers();
+    chip.tick_timers();
+    assert!(!chip.is_beeping());
+}